@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+/// Mirrors `/etc/servicer/system.toml`. Every field is optional so an empty or partial file
+/// still parses, falling back to systemd defaults.
+#[derive(Debug, Default, Deserialize)]
+pub struct BackendConfig {
+    /// Which backend to use: `systemd` (default), `openrc`, or `rc`.
+    pub init: Option<String>,
+    /// Command template used to check whether a service is running, e.g.
+    /// `"rc-service %name% status"`. `%name%` is substituted with the service name.
+    pub is_available: Option<String>,
+}
+
+const CONFIG_PATH: &str = "/etc/servicer/system.toml";
+
+/// Reads and parses `/etc/servicer/system.toml`, returning the all-defaults config when the
+/// file doesn't exist.
+pub fn load() -> BackendConfig {
+    match std::fs::read_to_string(CONFIG_PATH) {
+        Ok(contents) => toml::from_str(&contents)
+            .unwrap_or_else(|e| panic!("Failed to parse {CONFIG_PATH}: {e}")),
+        Err(_) => BackendConfig::default(),
+    }
+}
@@ -0,0 +1,182 @@
+mod config;
+mod openrc;
+mod rc;
+mod systemd;
+
+pub use config::BackendConfig;
+pub use openrc::OpenRcManager;
+pub use rc::RcManager;
+pub use systemd::SystemdManager;
+
+use std::path::PathBuf;
+
+/// When a service should be restarted by its supervisor. Mirrors systemd's `Restart=` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RestartPolicy {
+    No,
+    OnFailure,
+    Always,
+}
+
+impl RestartPolicy {
+    /// The systemd `Restart=` value.
+    pub fn as_systemd_str(&self) -> &'static str {
+        match self {
+            RestartPolicy::No => "no",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Always => "always",
+        }
+    }
+}
+
+/// `StartLimitIntervalSec=`/`StartLimitBurst=` pair: give up restarting after `burst` restarts
+/// within `interval_sec` seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartLimit {
+    pub burst: u32,
+    pub interval_sec: u32,
+}
+
+/// Resource caps rendered as systemd resource-control directives (`MemoryMax=`/`CPUQuota=`).
+#[derive(Debug, Clone, Default)]
+pub struct ResourceLimits {
+    pub memory_max: Option<String>,
+    pub cpu_quota_percent: Option<u32>,
+}
+
+/// Backend-neutral description of a service, built by `handle_create_service` and handed to
+/// whichever `ServiceManager` is active so unit rendering stays decoupled from the init system.
+pub struct ServiceSpec {
+    pub name: String,
+    pub command: Vec<String>,
+    pub directory: String,
+    pub user: String,
+    pub env_vars: Vec<String>,
+    pub restart_policy: RestartPolicy,
+    pub restart_sec: Option<u32>,
+    pub restart_limit: Option<RestartLimit>,
+    pub resource_limits: ResourceLimits,
+    /// Address to socket-activate on (`--listen`), e.g. `0.0.0.0:8080` or a unix socket path.
+    /// Only understood by backends that support socket activation (systemd).
+    pub listen: Option<String>,
+    /// Canonicalized path to a `.env`-style file (`--env-file`) to load alongside `env_vars`.
+    pub env_file: Option<String>,
+}
+
+impl ServiceSpec {
+    /// Whether the supervisor should ever restart this service, for backends (OpenRC, rc) that
+    /// only support a plain respawn toggle rather than a full restart policy.
+    pub fn auto_restart(&self) -> bool {
+        self.restart_policy != RestartPolicy::No
+    }
+}
+
+/// A init system capable of installing and driving a [`ServiceSpec`]. Implemented once per
+/// supported init system (systemd, OpenRC, BSD rc) so the rest of servicer doesn't need to know
+/// which one it's talking to.
+#[async_trait::async_trait]
+pub trait ServiceManager {
+    /// Path the rendered unit/script for `name` should be written to.
+    fn file_path(&self, name: &str) -> PathBuf;
+
+    /// Renders the unit/script contents for `spec`.
+    fn render_unit(&self, spec: &ServiceSpec) -> String;
+
+    async fn start(&self, name: &str) -> Result<(), Box<dyn std::error::Error>>;
+    async fn stop(&self, name: &str) -> Result<(), Box<dyn std::error::Error>>;
+    async fn enable(&self, name: &str) -> Result<(), Box<dyn std::error::Error>>;
+    async fn restart(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.stop(name).await?;
+        self.start(name).await
+    }
+
+    /// Human-readable status of servicer-managed units, for `ser status`.
+    async fn status(&self) -> Result<String, Box<dyn std::error::Error>>;
+
+    /// Whether `name` is currently running, via the configured `is_available` check command
+    /// (`/etc/servicer/system.toml`) or the backend's own default.
+    async fn is_running(&self, name: &str) -> bool;
+
+    /// Whether this backend can generate and drive a companion `.socket` unit for `--listen`.
+    /// Only systemd's unit model has a `.socket` unit type; OpenRC/rc have no equivalent.
+    fn supports_socket_activation(&self) -> bool {
+        false
+    }
+}
+
+/// Runs a `%name%`-templated check command (e.g. `rc-service %name% status`) and reports whether
+/// it exited successfully.
+pub(crate) async fn run_is_available_check(template: &str, name: &str) -> bool {
+    let command = template.replace("%name%", name);
+    let mut parts = command.split_whitespace();
+    let Some(program) = parts.next() else {
+        return false;
+    };
+    tokio::process::Command::new(program)
+        .args(parts)
+        .status()
+        .await
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Warns that `--restart-sec`/`--restart-limit`/`--memory-max`/`--cpu-quota` aren't supported, for
+/// backends (OpenRC, rc) with no equivalent of `RestartSec=`/`StartLimit*`/resource-control
+/// directives. Warns rather than silently dropping them, matching the `--env-file` permission
+/// warning.
+pub(crate) fn warn_unsupported_restart_and_resource_limits(spec: &ServiceSpec, backend_name: &str) {
+    if spec.restart_sec.is_some() || spec.restart_limit.is_some() {
+        eprintln!("Warning: --restart-sec and --restart-limit are not supported on the {backend_name} backend and will be ignored");
+    }
+    if spec.resource_limits.memory_max.is_some() || spec.resource_limits.cpu_quota_percent.is_some() {
+        eprintln!("Warning: --memory-max and --cpu-quota are not supported on the {backend_name} backend and will be ignored");
+    }
+}
+
+/// Picks the active backend from `/etc/servicer/system.toml`, defaulting to systemd when the
+/// file is absent or doesn't name one.
+pub fn resolve_backend() -> Box<dyn ServiceManager> {
+    let config = config::load();
+    match config.init.as_deref() {
+        Some("openrc") => Box::new(OpenRcManager::new(config)),
+        Some("rc") => Box::new(RcManager::new(config)),
+        Some("systemd") | None => Box::new(SystemdManager::new(config)),
+        Some(other) => panic!(
+            "Unknown init backend '{other}' in /etc/servicer/system.toml. Expected one of: systemd, openrc, rc"
+        ),
+    }
+}
+
+/// Minimal `ServiceSpec` for backend unit tests.
+#[cfg(test)]
+pub(crate) fn test_spec(name: &str) -> ServiceSpec {
+    ServiceSpec {
+        name: name.to_string(),
+        command: vec!["/usr/bin/node".to_string(), "index.js".to_string()],
+        directory: "/srv/app".to_string(),
+        user: "app".to_string(),
+        env_vars: vec![],
+        restart_policy: RestartPolicy::No,
+        restart_sec: None,
+        restart_limit: None,
+        resource_limits: ResourceLimits::default(),
+        listen: None,
+        env_file: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_is_available_check;
+
+    #[tokio::test]
+    async fn run_is_available_check_empty_template_is_false() {
+        assert!(!run_is_available_check("", "app.ser").await);
+    }
+
+    #[tokio::test]
+    async fn run_is_available_check_substitutes_name_and_checks_exit_status() {
+        assert!(run_is_available_check("true %name%", "app.ser").await);
+        assert!(!run_is_available_check("false %name%", "app.ser").await);
+    }
+}
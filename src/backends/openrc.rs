@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+use indoc::formatdoc;
+use tokio::process::Command;
+
+use crate::backends::{
+    run_is_available_check, warn_unsupported_restart_and_resource_limits, BackendConfig, RestartPolicy,
+    ServiceManager, ServiceSpec,
+};
+
+/// OpenRC backend: renders `/etc/init.d` scripts and drives them with `rc-service`/`rc-update`.
+pub struct OpenRcManager {
+    config: BackendConfig,
+}
+
+impl OpenRcManager {
+    pub fn new(config: BackendConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl ServiceManager for OpenRcManager {
+    fn file_path(&self, name: &str) -> PathBuf {
+        PathBuf::from("/etc/init.d").join(name)
+    }
+
+    fn render_unit(&self, spec: &ServiceSpec) -> String {
+        warn_unsupported_restart_and_resource_limits(spec, "OpenRC");
+        if spec.restart_policy == RestartPolicy::OnFailure {
+            eprintln!("Warning: --restart on-failure is not supported on the OpenRC backend; respawn will restart the service on any exit, not just failures");
+        }
+
+        let command = spec.command.join(" ");
+        let directory = &spec.directory;
+        let user = &spec.user;
+        let respawn = if spec.auto_restart() { "\n\trespawn" } else { "" };
+        let mut env_exports = spec
+            .env_vars
+            .iter()
+            .map(|var| format!("export {var}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if let Some(env_file) = &spec.env_file {
+            env_exports = format!(". \"{env_file}\"\n{env_exports}");
+        }
+        formatdoc! {
+            r#"
+          #!/sbin/openrc-run
+          # Generated with Servicer
+
+          name="{name}"
+          directory="{directory}"
+          command_user="{user}"
+          supervisor=supervise-daemon
+          command="{command}"{respawn}
+
+          {env_exports}
+
+          depend() {{
+          	need net
+          }}
+          "#,
+            name = spec.name,
+        }
+    }
+
+    async fn start(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("rc-service").args([name, "start"]).status().await?;
+        Ok(())
+    }
+
+    async fn stop(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("rc-service").args([name, "stop"]).status().await?;
+        Ok(())
+    }
+
+    async fn enable(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("rc-update")
+            .args(["add", name, "default"])
+            .status()
+            .await?;
+        Ok(())
+    }
+
+    async fn status(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let output = Command::new("rc-status").output().await?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    async fn is_running(&self, name: &str) -> bool {
+        let template = self
+            .config
+            .is_available
+            .as_deref()
+            .unwrap_or("rc-service %name% status");
+        run_is_available_check(template, name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::test_spec;
+
+    #[test]
+    fn render_unit_omits_respawn_by_default() {
+        let unit = OpenRcManager::new(Default::default()).render_unit(&test_spec("app.ser"));
+        assert!(!unit.contains("respawn"));
+    }
+
+    #[test]
+    fn render_unit_adds_respawn_when_auto_restart() {
+        let mut spec = test_spec("app.ser");
+        spec.restart_policy = RestartPolicy::Always;
+        let unit = OpenRcManager::new(Default::default()).render_unit(&spec);
+        assert!(unit.contains("respawn"));
+    }
+
+    #[test]
+    fn does_not_support_socket_activation() {
+        assert!(!OpenRcManager::new(Default::default()).supports_socket_activation());
+    }
+}
@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use indoc::formatdoc;
+use tokio::process::Command;
+
+use crate::backends::{
+    run_is_available_check, warn_unsupported_restart_and_resource_limits, BackendConfig, ServiceManager,
+    ServiceSpec,
+};
+
+/// BSD-style rc backend: renders `rc.subr` scripts under `/usr/local/etc/rc.d` and drives them
+/// with `service`/`sysrc`.
+pub struct RcManager {
+    config: BackendConfig,
+}
+
+impl RcManager {
+    pub fn new(config: BackendConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl ServiceManager for RcManager {
+    fn file_path(&self, name: &str) -> PathBuf {
+        PathBuf::from("/usr/local/etc/rc.d").join(name)
+    }
+
+    fn render_unit(&self, spec: &ServiceSpec) -> String {
+        warn_unsupported_restart_and_resource_limits(spec, "rc");
+        if spec.auto_restart() {
+            eprintln!("Warning: --restart and --auto-restart are not supported on the rc backend and will be ignored");
+        }
+
+        let name = &spec.name;
+        let command = spec.command.join(" ");
+        let directory = &spec.directory;
+        let user = &spec.user;
+        // A `#!/bin/sh` script: `setenv` is a csh/tcsh builtin, not POSIX sh, so use `export`.
+        let mut env_vars = spec
+            .env_vars
+            .iter()
+            .map(|var| format!("export {var}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if let Some(env_file) = &spec.env_file {
+            env_vars = format!(". \"{env_file}\"\n{env_vars}");
+        }
+        formatdoc! {
+            r#"
+          #!/bin/sh
+          # Generated with Servicer
+          # PROVIDE: {name}
+          # REQUIRE: NETWORKING
+
+          . /etc/rc.subr
+
+          name="{name}"
+          rcvar="{name}_enable"
+          command="/usr/sbin/daemon"
+          command_args="-u {user} -c {directory} {command}"
+
+          {env_vars}
+
+          load_rc_config $name
+          run_rc_command "$1"
+          "#
+        }
+    }
+
+    async fn start(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("service").args([name, "start"]).status().await?;
+        Ok(())
+    }
+
+    async fn stop(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("service").args([name, "stop"]).status().await?;
+        Ok(())
+    }
+
+    async fn enable(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Command::new("sysrc")
+            .arg(format!("{name}_enable=YES"))
+            .status()
+            .await?;
+        Ok(())
+    }
+
+    async fn status(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let output = Command::new("service").args(["-e"]).output().await?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    async fn is_running(&self, name: &str) -> bool {
+        let template = self
+            .config
+            .is_available
+            .as_deref()
+            .unwrap_or("service %name% status");
+        run_is_available_check(template, name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::test_spec;
+
+    #[test]
+    fn render_unit_includes_rcvar_and_command_args() {
+        let unit = RcManager::new(Default::default()).render_unit(&test_spec("app.ser"));
+        assert!(unit.contains(r#"rcvar="app.ser_enable""#));
+        assert!(unit.contains("command_args=\"-u app -c /srv/app /usr/bin/node index.js\""));
+    }
+
+    #[test]
+    fn does_not_support_socket_activation() {
+        assert!(!RcManager::new(Default::default()).supports_socket_activation());
+    }
+}
@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+
+use indoc::formatdoc;
+use tokio::process::Command;
+
+use crate::{
+    backends::{run_is_available_check, BackendConfig, ServiceManager, ServiceSpec},
+    utils::{
+        service_actions::{start_service, stop_service},
+        service_names::get_service_file_path,
+        systemd::ManagerProxy,
+    },
+};
+
+/// Resolves the unit file path for a per-user (`systemd --user`) service, honoring
+/// `$XDG_CONFIG_HOME` and falling back to `~/.config` like systemd itself does.
+fn user_service_file_path(name: &str) -> PathBuf {
+    let config_home = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            let home = std::env::var("HOME").expect("HOME is not set");
+            PathBuf::from(home).join(".config")
+        });
+    config_home.join("systemd/user").join(name)
+}
+
+/// Default backend: renders systemd unit files and drives them over D-Bus.
+pub struct SystemdManager {
+    config: BackendConfig,
+    /// Whether to talk to the user session bus (`--user-unit`) instead of the system bus.
+    user: bool,
+}
+
+impl SystemdManager {
+    pub fn new(config: BackendConfig) -> Self {
+        Self {
+            config,
+            user: false,
+        }
+    }
+
+    pub fn user(config: BackendConfig) -> Self {
+        Self {
+            config,
+            user: true,
+        }
+    }
+
+    async fn connect(&self) -> zbus::Result<zbus::Connection> {
+        if self.user {
+            zbus::Connection::session().await
+        } else {
+            zbus::Connection::system().await
+        }
+    }
+
+    /// Unit name for the companion `.socket` unit of `name` (a `.service` unit name).
+    pub fn socket_unit_name(&self, name: &str) -> String {
+        format!("{}.socket", name.trim_end_matches(".service"))
+    }
+
+    /// Path the companion `.socket` unit for `name` should be written to, alongside the service.
+    pub fn socket_file_path(&self, name: &str) -> PathBuf {
+        self.file_path(&self.socket_unit_name(name))
+    }
+
+    /// Renders the `[Socket]` unit that makes `name` socket-activated on `listen`.
+    pub fn render_socket_unit(&self, listen: &str) -> String {
+        formatdoc! {
+            r#"
+          # Generated with Servicer
+          [Unit]
+          After=network.target
+
+          [Socket]
+          ListenStream={listen}
+
+          [Install]
+          WantedBy=sockets.target
+          "#
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ServiceManager for SystemdManager {
+    fn file_path(&self, name: &str) -> PathBuf {
+        if self.user {
+            user_service_file_path(name)
+        } else {
+            get_service_file_path(name)
+        }
+    }
+
+    fn render_unit(&self, spec: &ServiceSpec) -> String {
+        let mut command = spec.command.join(" ");
+        command.push_str(&format!("\nRestart={}", spec.restart_policy.as_systemd_str()));
+        if let Some(restart_sec) = spec.restart_sec {
+            command.push_str(&format!("\nRestartSec={restart_sec}"));
+        }
+        if let Some(memory_max) = &spec.resource_limits.memory_max {
+            command.push_str(&format!("\nMemoryMax={memory_max}"));
+        }
+        if let Some(cpu_quota) = spec.resource_limits.cpu_quota_percent {
+            command.push_str(&format!("\nCPUQuota={cpu_quota}%"));
+        }
+        if let Some(env_file) = &spec.env_file {
+            command.push_str(&format!("\nEnvironmentFile={}", env_file));
+        }
+        for var in &spec.env_vars {
+            command.push_str(&format!("\nEnvironment={}", var));
+        }
+        let directory = &spec.directory;
+
+        // A user unit runs as whoever owns the session, so User= is meaningless (and rejected by
+        // systemd --user), and it isn't pulled in by multi-user.target, which only exists on the
+        // system manager; user units are activated via default.target instead.
+        let user_line = if self.user {
+            String::new()
+        } else {
+            format!("User={}\n\n", spec.user)
+        };
+        let wanted_by = if self.user { "default.target" } else { "multi-user.target" };
+
+        // StartLimitIntervalSec=/StartLimitBurst= are [Unit]-section directives; systemd warns
+        // and ignores them if they're stuffed into [Service] instead.
+        let mut unit_extra = String::new();
+        if let Some(limit) = spec.restart_limit {
+            unit_extra.push_str(&format!("\nStartLimitIntervalSec={}", limit.interval_sec));
+            unit_extra.push_str(&format!("\nStartLimitBurst={}", limit.burst));
+        }
+
+        formatdoc! {
+            r#"
+          # Generated with Servicer
+          [Unit]
+          After=network.target{unit_extra}
+
+          [Service]
+          Type=simple
+          {user_line}WorkingDirectory={directory}
+          ExecStart={command}
+
+          [Install]
+          WantedBy={wanted_by}
+          "#
+        }
+    }
+
+    async fn start(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let connection = self.connect().await?;
+        let manager_proxy = ManagerProxy::new(&connection).await?;
+        start_service(&manager_proxy, name).await;
+        Ok(())
+    }
+
+    async fn stop(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let connection = self.connect().await?;
+        let manager_proxy = ManagerProxy::new(&connection).await?;
+        stop_service(&manager_proxy, name).await;
+        Ok(())
+    }
+
+    async fn enable(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let connection = self.connect().await?;
+        let manager_proxy = ManagerProxy::new(&connection).await?;
+        manager_proxy.enable_unit_files(&[name], false, true).await?;
+        Ok(())
+    }
+
+    async fn status(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let mut command = Command::new("systemctl");
+        if self.user {
+            command.arg("--user");
+        }
+        command.args(["list-units", "*.ser.*", "--no-pager"]);
+        let output = command.output().await?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    async fn is_running(&self, name: &str) -> bool {
+        let default_check = if self.user {
+            "systemctl --user is-active %name%"
+        } else {
+            "systemctl is-active %name%"
+        };
+        let template = self.config.is_available.as_deref().unwrap_or(default_check);
+        run_is_available_check(template, name).await
+    }
+
+    fn supports_socket_activation(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::test_spec;
+
+    #[test]
+    fn render_unit_system_sets_user_and_system_target() {
+        let unit = SystemdManager::new(Default::default()).render_unit(&test_spec("app.ser.service"));
+        assert!(unit.contains("User=app"));
+        assert!(unit.contains("WantedBy=multi-user.target"));
+    }
+
+    #[test]
+    fn render_unit_user_omits_user_line_and_targets_default() {
+        let unit = SystemdManager::user(Default::default()).render_unit(&test_spec("app.ser.service"));
+        assert!(!unit.contains("User="));
+        assert!(unit.contains("WantedBy=default.target"));
+    }
+
+    #[test]
+    fn socket_unit_name_replaces_service_suffix() {
+        let manager = SystemdManager::new(Default::default());
+        assert_eq!(manager.socket_unit_name("app.ser.service"), "app.ser.socket");
+    }
+
+    #[test]
+    fn render_socket_unit_includes_listen_address() {
+        let unit = SystemdManager::new(Default::default()).render_socket_unit("0.0.0.0:8080");
+        assert!(unit.contains("ListenStream=0.0.0.0:8080"));
+    }
+
+    #[test]
+    fn supports_socket_activation() {
+        assert!(SystemdManager::new(Default::default()).supports_socket_activation());
+    }
+}
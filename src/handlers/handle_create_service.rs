@@ -1,18 +1,18 @@
 use clap::Parser;
-use indoc::formatdoc;
 use libc::LOG_AUTH;
-use std::{env, path::PathBuf};
+use std::{env, os::unix::fs::PermissionsExt, path::PathBuf};
 use tokio::fs;
 
 use crate::{
+    backends::{
+        resolve_backend, ResourceLimits, RestartLimit, RestartPolicy, ServiceSpec, SystemdManager,
+    },
     handlers::{
         handle_enable_service::handle_enable_service, handle_show_status::handle_show_status,
         handle_start_service::handle_start_service,
     },
-    utils::{
-        find_binary_path::find_binary_path,
-        service_names::{get_full_service_name, get_service_file_path},
-    },
+    nginx,
+    utils::{find_binary_path::find_binary_path, service_names::get_full_service_name},
 };
 
 /// Creates a new systemd service file.
@@ -53,11 +53,57 @@ pub struct CreateArgs {
     #[arg(short, long)]
     enable: bool,
 
-    /// Auto-restart on failure. Default false. You should edit the .service file for more advanced features.
-    /// The service must be enabled for auto-restart to work.
+    /// Auto-restart whenever the service exits. Default false. Shorthand for `--restart always`; prefer that
+    /// flag for finer control. The service must be enabled for auto-restart to work.
     #[arg(short = 'r', long)]
     auto_restart: bool,
 
+    /// Restart policy for the service: `no`, `on-failure`, or `always`. Defaults to `no`, or to
+    /// `always` if `--auto-restart` is set.
+    #[arg(long, value_enum)]
+    restart: Option<RestartPolicy>,
+
+    /// Seconds to wait before restarting the service (`RestartSec=`).
+    #[arg(long)]
+    restart_sec: Option<u32>,
+
+    /// Crash-loop protection as `<burst>:<interval-seconds>`, e.g. `5:30` gives up restarting
+    /// after 5 restarts within 30 seconds (`StartLimitBurst=`/`StartLimitIntervalSec=`).
+    #[arg(long)]
+    restart_limit: Option<String>,
+
+    /// Memory cap for the service, in systemd's byte-suffix notation (e.g. `512M`, `2G`)
+    /// (`MemoryMax=`).
+    #[arg(long)]
+    memory_max: Option<String>,
+
+    /// CPU quota for the service as a percentage of one core, e.g. `50` for 50% (`CPUQuota=`).
+    #[arg(long)]
+    cpu_quota: Option<u32>,
+
+    /// Socket-activate the service on this address (e.g. `0.0.0.0:8080` or a unix socket path)
+    /// instead of starting it immediately. Generates a companion `.socket` unit that is enabled
+    /// in place of the `.service`; systemd starts the service on first connection.
+    #[arg(long)]
+    listen: Option<String>,
+
+    /// Hostname to reverse-proxy to this service via nginx. Writes
+    /// `/etc/nginx/sites-available/{name}` (symlinked into `sites-enabled`) and reloads nginx.
+    #[arg(long)]
+    proxy: Option<String>,
+
+    /// Local port nginx should proxy to when `--proxy` is set. Inferred from `--listen` if that
+    /// names a `host:port` address and this is omitted.
+    #[arg(long)]
+    proxy_port: Option<u16>,
+
+    /// Create a per-user unit instead of a system-wide one. The unit is written to
+    /// `$XDG_CONFIG_HOME/systemd/user` (or `~/.config/systemd/user`) without a `User=` line, and
+    /// every lifecycle command for this service (`start`, `enable`, `restart`, `status`) talks to
+    /// the session bus instead of the system bus. Use this to run services without sudo.
+    #[arg(long = "user-unit")]
+    user_unit: bool,
+
     /// Optional custom interpreter. Input can be the executable's name, eg `python3` or the full path
     /// `usr/bin/python3`. If no input is provided servicer will use the file extension to detect the interpreter.
     #[arg(short, long)]
@@ -67,6 +113,12 @@ pub struct CreateArgs {
     #[arg(short = 'v', long)]
     env_vars: Vec<String>,
 
+    /// Path to a `.env`-style file to load via `EnvironmentFile=`, for services with more
+    /// environment variables than is practical to pass with `-v`. Combines with `--env-vars`.
+    /// Must exist at create time; servicer warns if it's readable by group or other users.
+    #[arg(long)]
+    env_file: Option<String>,
+
     /// Optional args passed to the file. Eg. to run `node index.js --foo bar` call `ser create index.js -- --foo bar`
     // #[arg(last = true)]
     command: Vec<String>,
@@ -82,8 +134,16 @@ pub async fn handle_create_service(args: CreateArgs) -> Result<(), Box<dyn std::
 
     let full_service_name = get_full_service_name(&service_name);
 
+    // `--user-unit` is systemd-specific (OpenRC/rc have no equivalent session manager), so it
+    // always uses the systemd backend directly rather than whatever `system.toml` selects.
+    let backend: Box<dyn crate::backends::ServiceManager> = if args.user_unit {
+        Box::new(SystemdManager::user(Default::default()))
+    } else {
+        resolve_backend()
+    };
+
     // Create file if it doesn't exist
-    let service_file_path = get_service_file_path(&full_service_name);
+    let service_file_path = backend.file_path(&full_service_name);
     let service_file_path_str = service_file_path.to_str().unwrap();
 
     if service_file_path.exists() {
@@ -135,20 +195,116 @@ pub async fn handle_create_service(args: CreateArgs) -> Result<(), Box<dyn std::
             }
         }
     }
-    let restart = args.auto_restart;
-    let service_body = create_service_file(command, directory, &user, args.env_vars, restart);
+    if args.listen.is_some() && args.user_unit {
+        panic!("--listen is not supported together with --user-unit yet");
+    }
+    if args.listen.is_some() && !backend.supports_socket_activation() {
+        panic!("--listen requires the systemd backend; the backend selected by /etc/servicer/system.toml doesn't support socket activation");
+    }
+
+    let env_file = if let Some(env_file) = args.env_file {
+        let canonical = fs::canonicalize(&env_file)
+            .await
+            .unwrap_or_else(|_| panic!("--env-file: {env_file} does not exist"));
+        if std::fs::metadata(&canonical).unwrap().permissions().mode() & 0o044 != 0 {
+            eprintln!(
+                "Warning: {} is readable by group/other; secrets in it are visible to other users on this machine",
+                canonical.to_str().unwrap()
+            );
+        }
+        Some(canonical.to_str().unwrap().to_string())
+    } else {
+        None
+    };
+
+    let restart_policy = args.restart.unwrap_or(if args.auto_restart {
+        RestartPolicy::Always
+    } else {
+        RestartPolicy::No
+    });
+    let restart_limit = args.restart_limit.map(|limit| {
+        let (burst, interval_sec) = limit
+            .split_once(':')
+            .expect("--restart-limit must be formatted as <burst>:<interval-seconds>, e.g. 5:30");
+        RestartLimit {
+            burst: burst.parse().expect("--restart-limit: invalid burst count"),
+            interval_sec: interval_sec
+                .parse()
+                .expect("--restart-limit: invalid interval"),
+        }
+    });
+
+    let spec = ServiceSpec {
+        name: full_service_name.clone(),
+        command,
+        directory: directory.to_string(),
+        user,
+        env_vars: args.env_vars,
+        restart_policy,
+        restart_sec: args.restart_sec,
+        restart_limit,
+        resource_limits: ResourceLimits {
+            memory_max: args.memory_max,
+            cpu_quota_percent: args.cpu_quota,
+        },
+        listen: args.listen,
+        env_file,
+    };
+    let service_body = backend.render_unit(&spec);
     if args.debug {
         print!("{}", service_body)
     } else {
+        if args.user_unit {
+            fs::create_dir_all(service_file_path.parent().unwrap())
+                .await
+                .unwrap();
+        }
         fs::write(&service_file_path, service_body).await.unwrap();
         println!("Service {service_name} created at {service_file_path_str}. To start run `ser start {service_name}`");
+
+        if spec.listen.is_some() {
+            // Guarded above: --listen requires a backend with supports_socket_activation(), which
+            // today only SystemdManager implements.
+            let systemd = SystemdManager::new(Default::default());
+            let socket_body = systemd.render_socket_unit(spec.listen.as_deref().unwrap());
+            let socket_path = systemd.socket_file_path(&full_service_name);
+            fs::write(&socket_path, socket_body).await.unwrap();
+            println!(
+                "Socket unit created at {}. It will be enabled instead of the service unit.",
+                socket_path.to_str().unwrap()
+            );
+        }
+
+        if let Some(hostname) = &args.proxy {
+            let port = args
+                .proxy_port
+                .or_else(|| {
+                    spec.listen
+                        .as_deref()
+                        .and_then(|listen| listen.rsplit_once(':'))
+                        .and_then(|(_, port)| port.parse().ok())
+                })
+                .expect("--proxy requires --proxy-port, or --listen with a host:port address");
+            nginx::create_proxy_site(&service_name, hostname, port)
+                .await
+                .unwrap();
+        }
+
         if args.start {
-            handle_start_service(&service_name, false).await.unwrap();
+            // With `--listen`, starting the socket unit is what makes the service listen;
+            // systemd starts the service itself on first connection.
+            handle_start_service(&service_name, false, args.user_unit, spec.listen.is_some())
+                .await
+                .unwrap();
         }
         if args.enable {
-            handle_enable_service(&service_name, false).await.unwrap();
+            // With `--listen`, the socket unit is what gets enabled; systemd starts the service
+            // itself on first connection.
+            handle_enable_service(&service_name, false, args.user_unit, spec.listen.is_some())
+                .await
+                .unwrap();
         }
-        handle_show_status().await?;
+        handle_show_status(args.user_unit).await?;
     }
     Ok(())
 }
@@ -169,40 +325,3 @@ fn get_interpreter(extension: Option<&std::ffi::OsStr>) -> Option<String> {
     };
     Some(i.to_string())
 }
-
-/// Creates a systemd service file at `/etc/systemd/system/{}.ser.service` and returns the unit name
-fn create_service_file(
-    command: Vec<String>,
-    directory: &str,
-    user: &str,
-    env_vars: Vec<String>,
-    auto_restart: bool,
-) -> String {
-    // This gets `root` instead of `hp` if sudo is used
-
-    let mut command = command.join(" ");
-
-    if auto_restart {
-        command.push_str("\nRestart=always");
-    }
-    for var in env_vars {
-        command.push_str(&format!("\nEnvironment={}", var));
-    }
-    formatdoc! {
-        r#"
-      # Generated with Servicer
-      [Unit]
-      After=network.target
-
-      [Service]
-      Type=simple
-      User={user}
-
-      WorkingDirectory={directory}
-      ExecStart={command}
-
-      [Install]
-      WantedBy=multi-user.target
-      "#
-    }
-}
@@ -1,38 +1,36 @@
 use crate::{
+    backends::{resolve_backend, ServiceManager, SystemdManager},
     handlers::handle_show_status::handle_show_status,
-    utils::{
-        service_actions::{start_service, stop_service}, 
-        service_names::get_full_service_name, 
-        systemd::ManagerProxy,
-    },
+    utils::service_names::get_full_service_name,
 };
 
 /// Restarts a service by stopping it and then starting it
 ///
 /// # Arguments
 ///
-/// * `name` - Name of the service to restart  
+/// * `name` - Name of the service to restart
 /// * `show_status` - Whether to show status after restart
+/// * `user_unit` - Whether this is a per-user systemd unit, in which case the session bus is
+///   used instead of the system bus (regardless of what `system.toml` selects)
 ///
 pub async fn handle_restart_service(
     name: &String,
     show_status: bool,
+    user_unit: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let full_service_name = get_full_service_name(&name);
 
-    let connection = zbus::Connection::system().await?;
-    let manager_proxy = ManagerProxy::new(&connection).await?;
-    
-    // Stop the service first
-    stop_service(&manager_proxy, &full_service_name).await;
-    println!("Stopped {name}");
-    
-    // Then start it
-    start_service(&manager_proxy, &full_service_name).await;
-    println!("Started {name}");
+    let backend: Box<dyn ServiceManager> = if user_unit {
+        Box::new(SystemdManager::user(Default::default()))
+    } else {
+        resolve_backend()
+    };
+
+    backend.restart(&full_service_name).await?;
+    println!("Restarted {name}");
 
     if show_status {
-        handle_show_status().await?;
+        handle_show_status(user_unit).await?;
     }
 
     Ok(())
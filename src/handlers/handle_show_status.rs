@@ -0,0 +1,20 @@
+use crate::backends::{resolve_backend, ServiceManager, SystemdManager};
+
+/// Prints the status of servicer-managed units.
+///
+/// # Arguments
+///
+/// * `user_unit` - Whether to query the session bus (for `--user-unit` services) instead of the
+///   system bus, or whatever `system.toml` otherwise selects
+///
+pub async fn handle_show_status(user_unit: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let backend: Box<dyn ServiceManager> = if user_unit {
+        Box::new(SystemdManager::user(Default::default()))
+    } else {
+        resolve_backend()
+    };
+
+    print!("{}", backend.status().await?);
+
+    Ok(())
+}
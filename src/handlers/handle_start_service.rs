@@ -0,0 +1,51 @@
+use crate::{
+    backends::{resolve_backend, ServiceManager, SystemdManager},
+    handlers::handle_show_status::handle_show_status,
+    utils::service_names::get_full_service_name,
+};
+
+/// Starts a service.
+///
+/// # Arguments
+///
+/// * `name` - Name of the service to start
+/// * `show_status` - Whether to show status after starting
+/// * `user_unit` - Whether this is a per-user systemd unit, in which case the session bus is
+///   used instead of the system bus (regardless of what `system.toml` selects)
+/// * `socket` - Whether `name` was created with `--listen`, in which case starting the companion
+///   `.socket` unit is what actually makes it listen; the `.service` itself starts on first
+///   connection
+///
+pub async fn handle_start_service(
+    name: &String,
+    show_status: bool,
+    user_unit: bool,
+    socket: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let full_service_name = get_full_service_name(name);
+
+    let backend: Box<dyn ServiceManager> = if user_unit {
+        Box::new(SystemdManager::user(Default::default()))
+    } else {
+        resolve_backend()
+    };
+
+    let unit_name = if socket {
+        SystemdManager::new(Default::default()).socket_unit_name(&full_service_name)
+    } else {
+        full_service_name
+    };
+
+    if backend.is_running(&unit_name).await {
+        println!("{name} is already running");
+    } else {
+        backend.start(&unit_name).await?;
+        println!("Started {name}");
+    }
+
+    if show_status {
+        handle_show_status(user_unit).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use indoc::formatdoc;
+use tokio::{fs, process::Command};
+
+const SITES_AVAILABLE: &str = "/etc/nginx/sites-available";
+const SITES_ENABLED: &str = "/etc/nginx/sites-enabled";
+
+/// Renders an nginx server block proxying `hostname` to `127.0.0.1:port`.
+fn render_site_block(hostname: &str, port: u16) -> String {
+    formatdoc! {r#"
+      # Generated with Servicer
+      server {{
+          listen 80;
+          server_name {hostname};
+
+          location / {{
+              proxy_pass http://127.0.0.1:{port};
+              proxy_set_header Host $host;
+              proxy_set_header X-Real-IP $remote_addr;
+              proxy_set_header X-Forwarded-For $proxy_add_x_forwarded_for;
+              proxy_set_header X-Forwarded-Proto $scheme;
+          }}
+      }}
+      "#
+    }
+}
+
+/// Writes an nginx site for `name` that reverse-proxies `hostname` to `127.0.0.1:port`, symlinks
+/// it into `sites-enabled`, and reloads nginx.
+pub async fn create_proxy_site(
+    name: &str,
+    hostname: &str,
+    port: u16,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let available_path = PathBuf::from(SITES_AVAILABLE).join(name);
+    let enabled_path = PathBuf::from(SITES_ENABLED).join(name);
+
+    fs::write(&available_path, render_site_block(hostname, port)).await?;
+
+    if !enabled_path.exists() {
+        std::os::unix::fs::symlink(&available_path, &enabled_path)?;
+    }
+
+    Command::new("nginx").args(["-s", "reload"]).status().await?;
+
+    println!(
+        "nginx site for {hostname} -> 127.0.0.1:{port} created at {}",
+        available_path.to_str().unwrap()
+    );
+    Ok(())
+}